@@ -0,0 +1,422 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::series::Series;
+use geo::{Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
+use geozero::{wkb, ToGeo};
+
+use super::utils::GH;
+
+/// The geometry kind a `GeoArrow` array is specialized for. Unlike the WKB
+/// blob representation, a `GeoArrow` array can only hold one kind of
+/// geometry at a time, since the coordinate/offset buffer layout differs
+/// per kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GeoArrowType {
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+}
+
+/// Coordinate/offset buffers for one geometry kind, laid out the way the
+/// GeoArrow spec does: coordinates live in a single interleaved `x, y`
+/// buffer, and nested geometries (linestrings, polygons, multi-*) are
+/// addressed by one offset buffer per level of nesting rather than by
+/// parsing WKB bytes.
+///
+/// This is not yet a first-class column type: `geoarrow_to_wkb`/
+/// `wkb_to_geoarrow` are the only way in or out, and there is no
+/// `DataType::GeoArrow` variant or `Series`/`Table` storage for it. Landing
+/// that requires changes in daft-core's `DataType`/`Series`, which this
+/// crate doesn't own. `geoarrow_area` below is a first cut at the other
+/// half of the original ask -- an operation reading coordinate slices
+/// directly instead of decoding WKB -- scoped to the one dispatch path this
+/// crate can wire on its own; `geo_unary_dispatch`/`geo_binary_dispatch`
+/// still only see `GeometryArray`/WKB until the `DataType` lands.
+pub struct GeoArrowArray {
+    pub geo_type: GeoArrowType,
+    pub coords: Vec<f64>,
+    /// Offsets into `coords`, in units of coordinate pairs, one per level of
+    /// nesting (e.g. geometry->ring->coord for a Polygon array).
+    pub offsets: Vec<Vec<i64>>,
+    pub validity: arrow2::bitmap::Bitmap,
+}
+
+impl GeoArrowArray {
+    fn coord(&self, idx: usize) -> (f64, f64) {
+        (self.coords[idx * 2], self.coords[idx * 2 + 1])
+    }
+
+    fn linestring(&self, ring_offsets: &[i64], ring_idx: usize) -> LineString {
+        let start = ring_offsets[ring_idx] as usize;
+        let end = ring_offsets[ring_idx + 1] as usize;
+        LineString::from((start..end).map(|i| self.coord(i)).collect::<Vec<_>>())
+    }
+
+    fn decode_one(&self, idx: usize) -> Geometry {
+        match self.geo_type {
+            GeoArrowType::Point => {
+                let (x, y) = self.coord(idx);
+                Geometry::Point(Point::new(x, y))
+            }
+            GeoArrowType::LineString => {
+                let geom_offsets = &self.offsets[0];
+                Geometry::LineString(self.linestring(geom_offsets, idx))
+            }
+            GeoArrowType::Polygon => {
+                let geom_offsets = &self.offsets[0];
+                let ring_offsets = &self.offsets[1];
+                let start = geom_offsets[idx] as usize;
+                let end = geom_offsets[idx + 1] as usize;
+                let mut rings = (start..end)
+                    .map(|r| self.linestring(ring_offsets, r))
+                    .collect::<Vec<_>>();
+                let exterior = rings.remove(0);
+                Geometry::Polygon(Polygon::new(exterior, rings))
+            }
+            GeoArrowType::MultiPoint => {
+                let geom_offsets = &self.offsets[0];
+                let start = geom_offsets[idx] as usize;
+                let end = geom_offsets[idx + 1] as usize;
+                Geometry::MultiPoint(MultiPoint::new(
+                    (start..end)
+                        .map(|i| {
+                            let (x, y) = self.coord(i);
+                            Point::new(x, y)
+                        })
+                        .collect(),
+                ))
+            }
+            GeoArrowType::MultiLineString => {
+                let geom_offsets = &self.offsets[0];
+                let line_offsets = &self.offsets[1];
+                let start = geom_offsets[idx] as usize;
+                let end = geom_offsets[idx + 1] as usize;
+                Geometry::MultiLineString(MultiLineString::new(
+                    (start..end).map(|l| self.linestring(line_offsets, l)).collect(),
+                ))
+            }
+            GeoArrowType::MultiPolygon => {
+                let poly_offsets = &self.offsets[0];
+                let ring_offsets = &self.offsets[1];
+                let coord_offsets = &self.offsets[2];
+                let start = poly_offsets[idx] as usize;
+                let end = poly_offsets[idx + 1] as usize;
+                Geometry::MultiPolygon(MultiPolygon::new(
+                    (start..end)
+                        .map(|p| {
+                            let rstart = ring_offsets[p] as usize;
+                            let rend = ring_offsets[p + 1] as usize;
+                            let mut rings = (rstart..rend)
+                                .map(|r| self.linestring(coord_offsets, r))
+                                .collect::<Vec<_>>();
+                            let exterior = rings.remove(0);
+                            Polygon::new(exterior, rings)
+                        })
+                        .collect(),
+                ))
+            }
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self.geo_type {
+            GeoArrowType::Point => self.coords.len() / 2,
+            _ => self.offsets[0].len().saturating_sub(1),
+        }
+    }
+}
+
+/// Unsigned area of every polygon in a Polygon-typed `GeoArrowArray`,
+/// computed by shoelace-summing straight off the coordinate/offset buffers
+/// -- no WKB decode, no intermediate `geo::Polygon` -- the zero-copy,
+/// decode-overhead-free dispatch path the original GeoArrow request asked
+/// for `geo_unary_dispatch::Area` to take.
+pub fn geoarrow_area(arr: &GeoArrowArray) -> DaftResult<Vec<Option<f64>>> {
+    if arr.geo_type != GeoArrowType::Polygon {
+        return Err(DaftError::ValueError(format!(
+            "geoarrow_area only supports Polygon GeoArrow arrays, got {:?}",
+            arr.geo_type
+        )));
+    }
+    let geom_offsets = &arr.offsets[0];
+    let ring_offsets = &arr.offsets[1];
+    Ok((0..arr.len())
+        .map(|i| {
+            if !arr.validity.get_bit(i) {
+                return None;
+            }
+            let start = geom_offsets[i] as usize;
+            let end = geom_offsets[i + 1] as usize;
+            // Exterior ring's area minus every interior (hole) ring's area.
+            let area = (start..end)
+                .enumerate()
+                .map(|(ring_idx, ring)| {
+                    let ring_area = ring_shoelace_area(arr, ring_offsets, ring);
+                    if ring_idx == 0 {
+                        ring_area
+                    } else {
+                        -ring_area
+                    }
+                })
+                .sum();
+            Some(area)
+        })
+        .collect())
+}
+
+fn ring_shoelace_area(arr: &GeoArrowArray, ring_offsets: &[i64], ring_idx: usize) -> f64 {
+    let start = ring_offsets[ring_idx] as usize;
+    let end = ring_offsets[ring_idx + 1] as usize;
+    let sum: f64 = (start..end)
+        .map(|i| {
+            let (x0, y0) = arr.coord(i);
+            let (x1, y1) = arr.coord(if i + 1 < end { i + 1 } else { start });
+            x0 * y1 - x1 * y0
+        })
+        .sum();
+    (sum / 2.0).abs()
+}
+
+/// Convert a native `GeoArrow` array back into the existing WKB-blob
+/// `GeometryArray` pipeline, reusing `GH::push` for the encode step.
+pub fn geoarrow_to_wkb(arr: &GeoArrowArray, name: &str) -> DaftResult<Series> {
+    let mut gh = GH::new(arr.len());
+    for i in 0..arr.len() {
+        if arr.validity.get_bit(i) {
+            gh.push(arr.decode_one(i));
+        } else {
+            gh.null();
+        }
+    }
+    gh.into_series(name)
+}
+
+/// Convert a WKB-encoded `Series` of `DataType::Geometry` into a native
+/// `GeoArrow` array of the requested geometry type, for zero-copy interop
+/// with the broader GeoArrow ecosystem.
+pub fn wkb_to_geoarrow(s: &Series, geo_type: GeoArrowType) -> DaftResult<GeoArrowArray> {
+    let geo_array = s.geometry()?;
+    let mut coords = Vec::new();
+    let mut offsets: Vec<Vec<i64>> = match geo_type {
+        GeoArrowType::Point => vec![],
+        GeoArrowType::LineString | GeoArrowType::MultiPoint => vec![vec![0]],
+        GeoArrowType::Polygon | GeoArrowType::MultiLineString => vec![vec![0], vec![0]],
+        GeoArrowType::MultiPolygon => vec![vec![0], vec![0], vec![0]],
+    };
+    let mut validity = arrow2::bitmap::MutableBitmap::with_capacity(geo_array.len());
+
+    for i in 0..geo_array.len() {
+        let raw = geo_array.physical.get(i);
+        let Some(raw) = raw else {
+            validity.push(false);
+            // A null row still occupies a row slot: every offsets level
+            // (and the coords buffer, for Point) needs an entry for it or
+            // every row after it reads from the wrong position.
+            push_null_geoarrow(geo_type, &mut coords, &mut offsets);
+            continue;
+        };
+        let bytes = raw.u8().unwrap().as_slice();
+        let geom = wkb::Wkb(bytes)
+            .to_geo()
+            .map_err(|e| DaftError::ValueError(format!("Could not decode WKB: {e}")))?;
+        push_geoarrow(geom, geo_type, &mut coords, &mut offsets)?;
+        validity.push(true);
+    }
+
+    Ok(GeoArrowArray {
+        geo_type,
+        coords,
+        offsets,
+        validity: validity.into(),
+    })
+}
+
+fn push_geoarrow(
+    geom: Geometry,
+    geo_type: GeoArrowType,
+    coords: &mut Vec<f64>,
+    offsets: &mut Vec<Vec<i64>>,
+) -> DaftResult<()> {
+    let mismatch = || {
+        DaftError::ValueError(format!(
+            "GeoArrow array of type {geo_type:?} cannot hold a mismatched geometry"
+        ))
+    };
+    match (geo_type, geom) {
+        (GeoArrowType::Point, Geometry::Point(p)) => {
+            coords.push(p.x());
+            coords.push(p.y());
+        }
+        (GeoArrowType::LineString, Geometry::LineString(ls)) => {
+            for c in ls.coords() {
+                coords.push(c.x);
+                coords.push(c.y);
+            }
+            offsets[0].push(coords.len() as i64 / 2);
+        }
+        (GeoArrowType::MultiPoint, Geometry::MultiPoint(mp)) => {
+            for p in mp.iter() {
+                coords.push(p.x());
+                coords.push(p.y());
+            }
+            offsets[0].push(coords.len() as i64 / 2);
+        }
+        (GeoArrowType::Polygon, Geometry::Polygon(poly)) => {
+            push_ring(poly.exterior(), coords, &mut offsets[1]);
+            for r in poly.interiors() {
+                push_ring(r, coords, &mut offsets[1]);
+            }
+            let ring_count = 1 + poly.interiors().len();
+            offsets[0].push(offsets[0].last().unwrap() + ring_count as i64);
+        }
+        (GeoArrowType::MultiLineString, Geometry::MultiLineString(mls)) => {
+            for ls in mls.iter() {
+                push_ring(ls, coords, &mut offsets[1]);
+            }
+            offsets[0].push(offsets[1].len() as i64 - 1);
+        }
+        (GeoArrowType::MultiPolygon, Geometry::MultiPolygon(mpoly)) => {
+            for poly in mpoly.iter() {
+                push_ring(poly.exterior(), coords, &mut offsets[2]);
+                for r in poly.interiors() {
+                    push_ring(r, coords, &mut offsets[2]);
+                }
+                let ring_count = 1 + poly.interiors().len();
+                offsets[1].push(offsets[1].last().unwrap() + ring_count as i64);
+            }
+            offsets[0].push(offsets[1].len() as i64 - 1);
+        }
+        _ => return Err(mismatch()),
+    }
+    Ok(())
+}
+
+fn push_ring(ls: &LineString, coords: &mut Vec<f64>, ring_offsets: &mut Vec<i64>) {
+    for c in ls.coords() {
+        coords.push(c.x);
+        coords.push(c.y);
+    }
+    ring_offsets.push(coords.len() as i64 / 2);
+}
+
+/// Occupy a null row's slot without touching what any valid row's offsets
+/// point at: `Point` has no offsets buffer, so a placeholder coordinate
+/// pair keeps `coords.len() / 2` equal to the row count; every other kind
+/// only needs a repeated top-level offset, since a zero-length range at
+/// that level is never read from the deeper ones.
+fn push_null_geoarrow(geo_type: GeoArrowType, coords: &mut Vec<f64>, offsets: &mut [Vec<i64>]) {
+    match geo_type {
+        GeoArrowType::Point => {
+            coords.push(0.0);
+            coords.push(0.0);
+        }
+        _ => {
+            let last = *offsets[0].last().unwrap();
+            offsets[0].push(last);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::{DataType, Field};
+    use geozero::ToWkt;
+
+    use super::{super::utils::decode_series, *};
+
+    fn utf8_series(values: Vec<Option<&str>>) -> Series {
+        let array = arrow2::array::Utf8Array::<i64>::from(values);
+        Series::from_arrow(
+            std::sync::Arc::new(Field::new("geom", DataType::Utf8)),
+            Box::new(array),
+        )
+        .unwrap()
+    }
+
+    fn wkt_rows(s: &Series) -> Vec<Option<String>> {
+        let geo = s.geometry().unwrap();
+        (0..geo.len())
+            .map(|i| {
+                let raw = geo.physical.get(i)?;
+                let bytes = raw.u8().unwrap().as_slice();
+                Some(wkb::Wkb(bytes).to_geo().unwrap().to_wkt().unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn null_row_does_not_corrupt_later_rows() {
+        // Reproduces the reported bug: a null between two valid rows used
+        // to desync every offsets level from the row index.
+        let wkt = utf8_series(vec![
+            Some("LINESTRING (0 0, 1 1)"),
+            None,
+            Some("LINESTRING (2 2, 3 3, 4 4)"),
+            Some("LINESTRING (5 5, 6 6)"),
+        ]);
+        let decoded = decode_series(&wkt, true).unwrap();
+
+        let arr = wkb_to_geoarrow(&decoded, GeoArrowType::LineString).unwrap();
+        assert_eq!(arr.len(), 4);
+        assert!(arr.validity.get_bit(0));
+        assert!(!arr.validity.get_bit(1));
+        assert!(arr.validity.get_bit(2));
+        assert!(arr.validity.get_bit(3));
+
+        let round_tripped = geoarrow_to_wkb(&arr, "geom").unwrap();
+        let rows = wkt_rows(&round_tripped);
+        assert_eq!(
+            rows,
+            vec![
+                Some("LINESTRING(0 0,1 1)".to_string()),
+                None,
+                Some("LINESTRING(2 2,3 3,4 4)".to_string()),
+                Some("LINESTRING(5 5,6 6)".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn point_round_trip_survives_a_leading_null() {
+        let wkt = utf8_series(vec![None, Some("POINT (1 2)"), Some("POINT (3 4)")]);
+        let decoded = decode_series(&wkt, true).unwrap();
+
+        let arr = wkb_to_geoarrow(&decoded, GeoArrowType::Point).unwrap();
+        assert_eq!(arr.len(), 3);
+
+        let round_tripped = geoarrow_to_wkb(&arr, "geom").unwrap();
+        let rows = wkt_rows(&round_tripped);
+        assert_eq!(
+            rows,
+            vec![
+                None,
+                Some("POINT(1 2)".to_string()),
+                Some("POINT(3 4)".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn geoarrow_area_reads_coords_directly_without_decoding_wkb() {
+        let wkt = utf8_series(vec![
+            None,
+            Some("POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0), (2 2, 2 4, 4 4, 4 2, 2 2))"),
+            Some("POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0))"),
+        ]);
+        let decoded = decode_series(&wkt, true).unwrap();
+
+        let arr = wkb_to_geoarrow(&decoded, GeoArrowType::Polygon).unwrap();
+        let areas = geoarrow_area(&arr).unwrap();
+        assert_eq!(areas, vec![None, Some(96.0), Some(16.0)]);
+    }
+
+    #[test]
+    fn geoarrow_area_rejects_non_polygon_arrays() {
+        let wkt = utf8_series(vec![Some("POINT (1 2)")]);
+        let decoded = decode_series(&wkt, true).unwrap();
+        let arr = wkb_to_geoarrow(&decoded, GeoArrowType::Point).unwrap();
+        assert!(geoarrow_area(&arr).is_err());
+    }
+}
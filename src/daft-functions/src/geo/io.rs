@@ -0,0 +1,678 @@
+use std::path::Path;
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    prelude::Schema,
+    series::{IntoSeries, Series},
+};
+use daft_table::Table;
+use geo::{Geometry, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
+use geojson::GeoJson;
+use shapefile::{dbase::FieldValue, Shape};
+
+use super::utils::{CoordDimTag, GH};
+
+/// A decoded shapefile geometry: either a plain `geo::Geometry`, or, for the
+/// Z/M shape variants `geo::Geometry` can't hold (it's 2D-only), pre-encoded
+/// WKB bytes tagged with their coordinate dimensionality -- the same split
+/// `decode_series` uses between `GH::push` and `GH::push_raw`.
+enum ShapeGeo {
+    Planar(Geometry),
+    Raw(Vec<u8>, CoordDimTag),
+}
+
+/// Read an ESRI Shapefile (`.shp` + its sibling `.dbf`) into a `Table`: a
+/// `geometry` column built via `GH` alongside one sibling `Series` per DBF
+/// attribute column.
+pub fn read_shapefile(path: impl AsRef<Path>) -> DaftResult<Table> {
+    let path = path.as_ref();
+    let mut reader = shapefile::Reader::from_path(path)
+        .map_err(|e| DaftError::ValueError(format!("Could not open shapefile {path:?}: {e}")))?;
+
+    let mut gh = GH::new(0);
+    let mut coord_dims = CoordDimTag::Xy;
+    let mut attribute_rows: Vec<std::collections::HashMap<String, FieldValue>> = Vec::new();
+
+    for result in reader.iter_shapes_and_records() {
+        let (shape, record) =
+            result.map_err(|e| DaftError::ValueError(format!("Could not read shape: {e}")))?;
+        match shape_to_geo(shape)? {
+            Some(ShapeGeo::Planar(geo)) => gh.push(geo),
+            Some(ShapeGeo::Raw(bytes, dims)) => {
+                coord_dims = dims;
+                gh.push_raw(&bytes);
+            }
+            None => gh.null(),
+        }
+        attribute_rows.push(record.into_iter().collect());
+    }
+
+    let geometry_series = gh.with_coord_dims(coord_dims).into_series("geometry")?;
+    let attribute_series = attribute_columns_to_series(&attribute_rows)?;
+
+    let mut columns = vec![geometry_series];
+    columns.extend(attribute_series);
+    let schema = Schema::new(columns.iter().map(|s| s.field().clone()).collect())?;
+    Table::new_with_size(schema, columns, attribute_rows.len())
+}
+
+fn shape_to_geo(shape: Shape) -> DaftResult<Option<ShapeGeo>> {
+    match shape {
+        Shape::NullShape => Ok(None),
+        Shape::Point(p) => Ok(Some(ShapeGeo::Planar(Geometry::Point(Point::new(p.x, p.y))))),
+        Shape::PointM(p) => Ok(Some(ShapeGeo::Raw(
+            point_wkb(&[p.x, p.y, p.m], CoordDimTag::Xym),
+            CoordDimTag::Xym,
+        ))),
+        // The shapefile spec stores both Z and an optional M alongside a
+        // PointZ, but M on a Z shape is rarely populated and geo::Geometry
+        // has no XYZM representation anyway; keep Z and drop M here, same
+        // as the other Z-variant shapes below.
+        Shape::PointZ(p) => Ok(Some(ShapeGeo::Raw(
+            point_wkb(&[p.x, p.y, p.z], CoordDimTag::Xyz),
+            CoordDimTag::Xyz,
+        ))),
+        Shape::Multipoint(mp) => Ok(Some(ShapeGeo::Planar(Geometry::MultiPoint(MultiPoint::new(
+            mp.points().iter().map(|p| Point::new(p.x, p.y)).collect(),
+        ))))),
+        Shape::MultipointM(mp) => Ok(Some(ShapeGeo::Raw(
+            multipoint_wkb(
+                &mp.points()
+                    .iter()
+                    .map(|p| vec![p.x, p.y, p.m])
+                    .collect::<Vec<_>>(),
+                CoordDimTag::Xym,
+            ),
+            CoordDimTag::Xym,
+        ))),
+        Shape::MultipointZ(mp) => Ok(Some(ShapeGeo::Raw(
+            multipoint_wkb(
+                &mp.points()
+                    .iter()
+                    .map(|p| vec![p.x, p.y, p.z])
+                    .collect::<Vec<_>>(),
+                CoordDimTag::Xyz,
+            ),
+            CoordDimTag::Xyz,
+        ))),
+        Shape::Polyline(pl) => {
+            let lines: Vec<LineString> = pl
+                .parts()
+                .iter()
+                .map(|part| LineString::from(part.iter().map(|p| (p.x, p.y)).collect::<Vec<_>>()))
+                .collect();
+            Ok(Some(ShapeGeo::Planar(if lines.len() == 1 {
+                Geometry::LineString(lines.into_iter().next().unwrap())
+            } else {
+                Geometry::MultiLineString(MultiLineString::new(lines))
+            })))
+        }
+        Shape::PolylineM(pl) => {
+            let lines: Vec<Vec<Vec<f64>>> = pl
+                .parts()
+                .iter()
+                .map(|part| part.iter().map(|p| vec![p.x, p.y, p.m]).collect())
+                .collect();
+            Ok(Some(ShapeGeo::Raw(
+                polyline_wkb(&lines, CoordDimTag::Xym),
+                CoordDimTag::Xym,
+            )))
+        }
+        Shape::PolylineZ(pl) => {
+            let lines: Vec<Vec<Vec<f64>>> = pl
+                .parts()
+                .iter()
+                .map(|part| part.iter().map(|p| vec![p.x, p.y, p.z]).collect())
+                .collect();
+            Ok(Some(ShapeGeo::Raw(
+                polyline_wkb(&lines, CoordDimTag::Xyz),
+                CoordDimTag::Xyz,
+            )))
+        }
+        Shape::Polygon(poly) => {
+            let rings: Vec<Vec<Vec<f64>>> = poly
+                .rings()
+                .iter()
+                .map(|ring| ring.points().iter().map(|p| vec![p.x, p.y]).collect())
+                .collect();
+            let mut polygons: Vec<Polygon> = group_polygon_rings(rings)
+                .into_iter()
+                .map(|(exterior, holes)| {
+                    Polygon::new(
+                        LineString::from(exterior.iter().map(|c| (c[0], c[1])).collect::<Vec<_>>()),
+                        holes
+                            .into_iter()
+                            .map(|h| LineString::from(h.iter().map(|c| (c[0], c[1])).collect::<Vec<_>>()))
+                            .collect(),
+                    )
+                })
+                .collect();
+            Ok(match polygons.len() {
+                0 => None,
+                1 => Some(ShapeGeo::Planar(Geometry::Polygon(polygons.remove(0)))),
+                _ => Some(ShapeGeo::Planar(Geometry::MultiPolygon(MultiPolygon::new(
+                    polygons,
+                )))),
+            })
+        }
+        Shape::PolygonM(poly) => {
+            let rings: Vec<Vec<Vec<f64>>> = poly
+                .rings()
+                .iter()
+                .map(|ring| ring.points().iter().map(|p| vec![p.x, p.y, p.m]).collect())
+                .collect();
+            Ok(polygon_rings_to_geo(rings, CoordDimTag::Xym))
+        }
+        Shape::PolygonZ(poly) => {
+            let rings: Vec<Vec<Vec<f64>>> = poly
+                .rings()
+                .iter()
+                .map(|ring| ring.points().iter().map(|p| vec![p.x, p.y, p.z]).collect())
+                .collect();
+            Ok(polygon_rings_to_geo(rings, CoordDimTag::Xyz))
+        }
+        // A Multipatch describes a TIN surface (triangle strips/fans), not
+        // a linear-ring geometry -- there's no sound mapping to `geo`'s
+        // Polygon/MultiPolygon, so raise rather than silently emit a null
+        // (or worse, a wrong) geometry.
+        Shape::Multipatch(_) => Err(DaftError::ValueError(
+            "Shapefile Multipatch shapes are not supported (no linear-ring geometry mapping)"
+                .to_string(),
+        )),
+    }
+}
+
+/// Group a shapefile polygon's rings (each a `Vec` of coordinate vectors)
+/// into shells + holes by winding order -- a clockwise ring (per the ESRI
+/// shapefile spec, with y increasing upward) starts a new shell, and a
+/// counterclockwise ring is a hole of the most recent shell -- instead of
+/// assuming ring 0 is the only shell. A polygon with multiple disjoint
+/// shells (e.g. islands) needs to become a `MultiPolygon`, not a `Polygon`
+/// with the wrong holes.
+fn group_polygon_rings(rings: Vec<Vec<Vec<f64>>>) -> Vec<(Vec<Vec<f64>>, Vec<Vec<Vec<f64>>>)> {
+    let mut groups: Vec<(Vec<Vec<f64>>, Vec<Vec<Vec<f64>>>)> = Vec::new();
+    for points in rings {
+        if groups.is_empty() || ring_is_clockwise(&points) {
+            groups.push((points, Vec::new()));
+        } else {
+            groups.last_mut().unwrap().1.push(points);
+        }
+    }
+    groups
+}
+
+/// Whether a closed ring's points wind clockwise, viewed with y increasing
+/// upward. Only the x/y components of each coordinate matter.
+fn ring_is_clockwise(points: &[Vec<f64>]) -> bool {
+    let signed_area_x2: f64 = points
+        .windows(2)
+        .map(|w| w[0][0] * w[1][1] - w[1][0] * w[0][1])
+        .sum();
+    signed_area_x2 < 0.0
+}
+
+/// `Shape::PolygonZ`/`PolygonM` can't round trip through `geo::Polygon`
+/// (2D-only), so group their rings the same way `Shape::Polygon` does and
+/// WKB-encode the result directly at `dims`.
+fn polygon_rings_to_geo(rings: Vec<Vec<Vec<f64>>>, dims: CoordDimTag) -> Option<ShapeGeo> {
+    let groups = group_polygon_rings(rings);
+    if groups.is_empty() {
+        return None;
+    }
+    let polys: Vec<Vec<Vec<Vec<f64>>>> = groups
+        .into_iter()
+        .map(|(exterior, holes)| {
+            let mut rings = vec![exterior];
+            rings.extend(holes);
+            rings
+        })
+        .collect();
+    let bytes = if polys.len() == 1 {
+        polygon_wkb(&polys[0], dims)
+    } else {
+        multipolygon_wkb(&polys, dims)
+    };
+    Some(ShapeGeo::Raw(bytes, dims))
+}
+
+const WKB_POINT: u32 = 1;
+const WKB_LINESTRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTIPOINT: u32 = 4;
+const WKB_MULTILINESTRING: u32 = 5;
+const WKB_MULTIPOLYGON: u32 = 6;
+
+/// ISO-extended WKB geometry-type code for `dims`: +1000 for Z, +2000 for
+/// M, +3000 for ZM. `utils::wkb_coord_dims` already knows how to parse
+/// these back out, so encoding them here is what lets a Z/M shapefile
+/// round trip through `decode_series`/`to_wkb`/`to_wkt` unchanged.
+fn iso_type_code(base: u32, dims: CoordDimTag) -> u32 {
+    base + match dims {
+        CoordDimTag::Xy => 0,
+        CoordDimTag::Xyz => 1000,
+        CoordDimTag::Xym => 2000,
+        CoordDimTag::Xyzm => 3000,
+    }
+}
+
+fn wkb_header(buf: &mut Vec<u8>, base_type: u32, dims: CoordDimTag) {
+    buf.push(1); // little endian
+    buf.extend_from_slice(&iso_type_code(base_type, dims).to_le_bytes());
+}
+
+fn write_coord(buf: &mut Vec<u8>, coord: &[f64]) {
+    for v in coord {
+        buf.extend_from_slice(&v.to_le_bytes());
+    }
+}
+
+fn write_ring(buf: &mut Vec<u8>, points: &[Vec<f64>]) {
+    buf.extend_from_slice(&(points.len() as u32).to_le_bytes());
+    for p in points {
+        write_coord(buf, p);
+    }
+}
+
+fn point_wkb(coord: &[f64], dims: CoordDimTag) -> Vec<u8> {
+    let mut buf = Vec::new();
+    wkb_header(&mut buf, WKB_POINT, dims);
+    write_coord(&mut buf, coord);
+    buf
+}
+
+fn linestring_wkb(points: &[Vec<f64>], dims: CoordDimTag) -> Vec<u8> {
+    let mut buf = Vec::new();
+    wkb_header(&mut buf, WKB_LINESTRING, dims);
+    write_ring(&mut buf, points);
+    buf
+}
+
+fn polygon_wkb(rings: &[Vec<Vec<f64>>], dims: CoordDimTag) -> Vec<u8> {
+    let mut buf = Vec::new();
+    wkb_header(&mut buf, WKB_POLYGON, dims);
+    buf.extend_from_slice(&(rings.len() as u32).to_le_bytes());
+    for ring in rings {
+        write_ring(&mut buf, ring);
+    }
+    buf
+}
+
+fn multipoint_wkb(points: &[Vec<f64>], dims: CoordDimTag) -> Vec<u8> {
+    let mut buf = Vec::new();
+    wkb_header(&mut buf, WKB_MULTIPOINT, dims);
+    buf.extend_from_slice(&(points.len() as u32).to_le_bytes());
+    for p in points {
+        buf.extend_from_slice(&point_wkb(p, dims));
+    }
+    buf
+}
+
+fn multilinestring_wkb(lines: &[Vec<Vec<f64>>], dims: CoordDimTag) -> Vec<u8> {
+    let mut buf = Vec::new();
+    wkb_header(&mut buf, WKB_MULTILINESTRING, dims);
+    buf.extend_from_slice(&(lines.len() as u32).to_le_bytes());
+    for line in lines {
+        buf.extend_from_slice(&linestring_wkb(line, dims));
+    }
+    buf
+}
+
+/// A shapefile Polyline part is a single linestring if there's only one
+/// part, else a MultiLineString -- same rule `Shape::Polyline` above uses.
+fn polyline_wkb(lines: &[Vec<Vec<f64>>], dims: CoordDimTag) -> Vec<u8> {
+    if lines.len() == 1 {
+        linestring_wkb(&lines[0], dims)
+    } else {
+        multilinestring_wkb(lines, dims)
+    }
+}
+
+fn multipolygon_wkb(polys: &[Vec<Vec<Vec<f64>>>], dims: CoordDimTag) -> Vec<u8> {
+    let mut buf = Vec::new();
+    wkb_header(&mut buf, WKB_MULTIPOLYGON, dims);
+    buf.extend_from_slice(&(polys.len() as u32).to_le_bytes());
+    for poly in polys {
+        buf.extend_from_slice(&polygon_wkb(poly, dims));
+    }
+    buf
+}
+
+/// GeoJSON has no separate M concept (RFC 7946 says a third position
+/// component is "commonly" elevation), so a 3-component position is always
+/// treated as Z.
+fn position_dims(pos: &[f64]) -> CoordDimTag {
+    if pos.len() >= 3 {
+        CoordDimTag::Xyz
+    } else {
+        CoordDimTag::Xy
+    }
+}
+
+fn any_position_has_z(value: &geojson::Value) -> bool {
+    use geojson::Value;
+    match value {
+        Value::Point(p) => p.len() >= 3,
+        Value::MultiPoint(ps) | Value::LineString(ps) => ps.iter().any(|p| p.len() >= 3),
+        Value::MultiLineString(pss) | Value::Polygon(pss) => {
+            pss.iter().any(|ps| ps.iter().any(|p| p.len() >= 3))
+        }
+        Value::MultiPolygon(psss) => psss
+            .iter()
+            .any(|pss| pss.iter().any(|ps| ps.iter().any(|p| p.len() >= 3))),
+        Value::GeometryCollection(geoms) => geoms.iter().any(|g| any_position_has_z(&g.value)),
+    }
+}
+
+/// Convert a GeoJSON geometry value into a `ShapeGeo`, the same split
+/// `shape_to_geo` uses: a plain `geo::Geometry` when every position is 2D,
+/// or raw WKB bytes tagged with their dimensionality when a Z component is
+/// present, since `geo_types::Geometry` (and `geo_types::Geometry::try_from`,
+/// which the old code relied on exclusively) is 2D-only and would silently
+/// drop it.
+fn geojson_value_to_shape_geo(value: &geojson::Value) -> DaftResult<Option<ShapeGeo>> {
+    use geojson::Value;
+    match value {
+        Value::Point(pos) => Ok(Some(match position_dims(pos) {
+            CoordDimTag::Xy => ShapeGeo::Planar(Geometry::Point(Point::new(pos[0], pos[1]))),
+            dims => ShapeGeo::Raw(point_wkb(pos, dims), dims),
+        })),
+        Value::MultiPoint(positions) => {
+            let dims = positions.first().map_or(CoordDimTag::Xy, |p| position_dims(p));
+            Ok(Some(match dims {
+                CoordDimTag::Xy => ShapeGeo::Planar(Geometry::MultiPoint(MultiPoint::new(
+                    positions.iter().map(|p| Point::new(p[0], p[1])).collect(),
+                ))),
+                _ => ShapeGeo::Raw(multipoint_wkb(positions, dims), dims),
+            }))
+        }
+        Value::LineString(positions) => {
+            let dims = positions.first().map_or(CoordDimTag::Xy, |p| position_dims(p));
+            Ok(Some(match dims {
+                CoordDimTag::Xy => ShapeGeo::Planar(Geometry::LineString(LineString::from(
+                    positions.iter().map(|p| (p[0], p[1])).collect::<Vec<_>>(),
+                ))),
+                _ => ShapeGeo::Raw(linestring_wkb(positions, dims), dims),
+            }))
+        }
+        Value::MultiLineString(lines) => {
+            let dims = lines
+                .first()
+                .and_then(|l| l.first())
+                .map_or(CoordDimTag::Xy, |p| position_dims(p));
+            Ok(Some(match dims {
+                CoordDimTag::Xy => ShapeGeo::Planar(Geometry::MultiLineString(MultiLineString::new(
+                    lines
+                        .iter()
+                        .map(|l| LineString::from(l.iter().map(|p| (p[0], p[1])).collect::<Vec<_>>()))
+                        .collect(),
+                ))),
+                _ => ShapeGeo::Raw(multilinestring_wkb(lines, dims), dims),
+            }))
+        }
+        // A GeoJSON Polygon's rings are already ring 0 = exterior, rest =
+        // holes by spec (unlike shapefile, which allows multiple exterior
+        // rings in one Polygon shape), so no winding-order grouping needed.
+        Value::Polygon(rings) => {
+            if rings.is_empty() {
+                return Ok(None);
+            }
+            let dims = rings
+                .first()
+                .and_then(|r| r.first())
+                .map_or(CoordDimTag::Xy, |p| position_dims(p));
+            Ok(Some(match dims {
+                CoordDimTag::Xy => {
+                    let mut rings = rings
+                        .iter()
+                        .map(|r| LineString::from(r.iter().map(|p| (p[0], p[1])).collect::<Vec<_>>()));
+                    let exterior = rings.next().unwrap();
+                    ShapeGeo::Planar(Geometry::Polygon(Polygon::new(exterior, rings.collect())))
+                }
+                _ => ShapeGeo::Raw(polygon_wkb(rings, dims), dims),
+            }))
+        }
+        Value::MultiPolygon(polys) => {
+            if polys.is_empty() {
+                return Ok(None);
+            }
+            let dims = polys
+                .first()
+                .and_then(|p| p.first())
+                .and_then(|r| r.first())
+                .map_or(CoordDimTag::Xy, |p| position_dims(p));
+            Ok(Some(match dims {
+                CoordDimTag::Xy => ShapeGeo::Planar(Geometry::MultiPolygon(MultiPolygon::new(
+                    polys
+                        .iter()
+                        .map(|rings| {
+                            let mut rings = rings.iter().map(|r| {
+                                LineString::from(r.iter().map(|p| (p[0], p[1])).collect::<Vec<_>>())
+                            });
+                            let exterior = rings.next().unwrap();
+                            Polygon::new(exterior, rings.collect())
+                        })
+                        .collect(),
+                ))),
+                _ => ShapeGeo::Raw(multipolygon_wkb(polys, dims), dims),
+            }))
+        }
+        Value::GeometryCollection(_) => {
+            if any_position_has_z(value) {
+                return Err(DaftError::ValueError(
+                    "GeoJSON GeometryCollection with Z coordinates is not supported".to_string(),
+                ));
+            }
+            match geo_types::Geometry::<f64>::try_from(value.clone()) {
+                Ok(geo) => Ok(Some(ShapeGeo::Planar(geo))),
+                Err(_) => Ok(None),
+            }
+        }
+    }
+}
+
+fn attribute_columns_to_series(
+    rows: &[std::collections::HashMap<String, FieldValue>],
+) -> DaftResult<Vec<Series>> {
+    let mut names: Vec<String> = Vec::new();
+    for row in rows {
+        for key in row.keys() {
+            if !names.contains(key) {
+                names.push(key.clone());
+            }
+        }
+    }
+
+    names
+        .into_iter()
+        .map(|name| {
+            let values: Vec<Option<String>> = rows
+                .iter()
+                .map(|row| row.get(&name).map(field_value_to_string))
+                .collect();
+            let arrow_array = arrow2::array::Utf8Array::<i64>::from(values);
+            Series::from_arrow(
+                std::sync::Arc::new(daft_core::prelude::Field::new(
+                    name,
+                    daft_core::prelude::DataType::Utf8,
+                )),
+                Box::new(arrow_array),
+            )
+        })
+        .collect()
+}
+
+fn field_value_to_string(v: &FieldValue) -> String {
+    match v {
+        FieldValue::Character(Some(s)) => s.clone(),
+        FieldValue::Numeric(Some(n)) => n.to_string(),
+        FieldValue::Logical(Some(b)) => b.to_string(),
+        other => format!("{other:?}"),
+    }
+}
+
+/// Read a GeoJSON file into a `Table`, pairing the `geometry` column with
+/// sibling attribute `Series` built from each feature's `properties`.
+pub fn read_geojson(path: impl AsRef<Path>) -> DaftResult<Table> {
+    let path = path.as_ref();
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| DaftError::ValueError(format!("Could not read {path:?}: {e}")))?;
+    let geojson = contents
+        .parse::<GeoJson>()
+        .map_err(|e| DaftError::ValueError(format!("Could not parse GeoJSON: {e}")))?;
+
+    let features = match geojson {
+        GeoJson::FeatureCollection(fc) => fc.features,
+        GeoJson::Feature(f) => vec![f],
+        GeoJson::Geometry(g) => {
+            vec![geojson::Feature {
+                bbox: None,
+                geometry: Some(g),
+                id: None,
+                properties: None,
+                foreign_members: None,
+            }]
+        }
+    };
+
+    let mut gh = GH::new(features.len());
+    let mut coord_dims = CoordDimTag::Xy;
+    let mut attribute_rows = Vec::with_capacity(features.len());
+    for feature in &features {
+        match &feature.geometry {
+            Some(g) => match geojson_value_to_shape_geo(&g.value)? {
+                Some(ShapeGeo::Planar(geo)) => gh.push(geo),
+                Some(ShapeGeo::Raw(bytes, dims)) => {
+                    coord_dims = dims;
+                    gh.push_raw(&bytes);
+                }
+                None => gh.null(),
+            },
+            None => gh.null(),
+        }
+        attribute_rows.push(
+            feature
+                .properties
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .collect::<std::collections::HashMap<_, _>>(),
+        );
+    }
+
+    let geometry_series = gh.with_coord_dims(coord_dims).into_series("geometry")?;
+    let attribute_series = geojson_properties_to_series(&attribute_rows)?;
+
+    let mut columns = vec![geometry_series];
+    columns.extend(attribute_series);
+    let schema = Schema::new(columns.iter().map(|s| s.field().clone()).collect())?;
+    Table::new_with_size(schema, columns, features.len())
+}
+
+fn geojson_properties_to_series(
+    rows: &[std::collections::HashMap<String, serde_json::Value>],
+) -> DaftResult<Vec<Series>> {
+    let mut names: Vec<String> = Vec::new();
+    for row in rows {
+        for key in row.keys() {
+            if !names.contains(key) {
+                names.push(key.clone());
+            }
+        }
+    }
+
+    names
+        .into_iter()
+        .map(|name| {
+            let values: Vec<Option<String>> = rows
+                .iter()
+                .map(|row| row.get(&name).map(|v| v.to_string()))
+                .collect();
+            let arrow_array = arrow2::array::Utf8Array::<i64>::from(values);
+            Series::from_arrow(
+                std::sync::Arc::new(daft_core::prelude::Field::new(
+                    name,
+                    daft_core::prelude::DataType::Utf8,
+                )),
+                Box::new(arrow_array),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use geozero::{wkb, ToWkt};
+
+    use super::*;
+
+    #[test]
+    fn geojson_planar_point_stays_a_geo_geometry() {
+        let value = geojson::Value::Point(vec![1.0, 2.0]);
+        match geojson_value_to_shape_geo(&value).unwrap().unwrap() {
+            ShapeGeo::Planar(Geometry::Point(p)) => assert_eq!((p.x(), p.y()), (1.0, 2.0)),
+            _ => panic!("expected a planar Point"),
+        }
+    }
+
+    #[test]
+    fn geojson_point_with_z_is_encoded_as_raw_xyz_wkb_instead_of_flattened() {
+        let value = geojson::Value::Point(vec![1.0, 2.0, 3.0]);
+        match geojson_value_to_shape_geo(&value).unwrap().unwrap() {
+            ShapeGeo::Raw(bytes, CoordDimTag::Xyz) => {
+                let wkt = wkb::Wkb(bytes).to_wkt().unwrap();
+                assert_eq!(wkt, "POINT Z(1 2 3)");
+            }
+            _ => panic!("expected raw Xyz WKB, not a flattened planar Point"),
+        }
+    }
+
+    #[test]
+    fn geojson_polygon_with_z_round_trips_through_wkb() {
+        let value = geojson::Value::Polygon(vec![vec![
+            vec![0.0, 0.0, 10.0],
+            vec![2.0, 0.0, 10.0],
+            vec![2.0, 2.0, 10.0],
+            vec![0.0, 0.0, 10.0],
+        ]]);
+        match geojson_value_to_shape_geo(&value).unwrap().unwrap() {
+            ShapeGeo::Raw(bytes, CoordDimTag::Xyz) => {
+                let wkt = wkb::Wkb(bytes).to_wkt().unwrap();
+                assert_eq!(wkt, "POLYGON Z((0 0 10,2 0 10,2 2 10,0 0 10))");
+            }
+            _ => panic!("expected raw Xyz WKB for a Z-bearing polygon"),
+        }
+    }
+
+    #[test]
+    fn geojson_geometry_collection_with_z_is_rejected_instead_of_flattened() {
+        let value = geojson::Value::GeometryCollection(vec![geojson::Geometry::new(
+            geojson::Value::Point(vec![1.0, 2.0, 3.0]),
+        )]);
+        assert!(geojson_value_to_shape_geo(&value).is_err());
+    }
+
+    #[test]
+    fn group_polygon_rings_splits_on_winding_order() {
+        let exterior = vec![
+            vec![0.0, 0.0],
+            vec![0.0, 10.0],
+            vec![10.0, 10.0],
+            vec![10.0, 0.0],
+            vec![0.0, 0.0],
+        ];
+        let hole = vec![
+            vec![2.0, 2.0],
+            vec![4.0, 2.0],
+            vec![4.0, 4.0],
+            vec![2.0, 4.0],
+            vec![2.0, 2.0],
+        ];
+        assert!(ring_is_clockwise(&exterior));
+        assert!(!ring_is_clockwise(&hole));
+
+        let groups = group_polygon_rings(vec![exterior.clone(), hole.clone()]);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].0, exterior);
+        assert_eq!(groups[0].1, vec![hole]);
+    }
+}
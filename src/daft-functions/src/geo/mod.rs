@@ -0,0 +1,9 @@
+mod geoarrow;
+mod io;
+mod rtree;
+mod utils;
+
+pub use geoarrow::{geoarrow_area, geoarrow_to_wkb, wkb_to_geoarrow, GeoArrowArray, GeoArrowType};
+pub use io::{read_geojson, read_shapefile};
+pub use rtree::{geo_spatial_join, RTree, SpatialJoinIndices};
+pub use utils::*;
@@ -0,0 +1,233 @@
+use common_error::DaftResult;
+use geo::{BoundingRect, Contains, Geometry, Intersects};
+
+use super::utils::{GeoOperation, GeometryArrayIter};
+
+/// An axis-aligned bounding box, `(minx, miny, maxx, maxy)`.
+type Envelope = (f64, f64, f64, f64);
+
+fn envelope(geom: &Geometry) -> Option<Envelope> {
+    let rect = geom.bounding_rect()?;
+    Some((rect.min().x, rect.min().y, rect.max().x, rect.max().y))
+}
+
+fn envelopes_intersect(a: Envelope, b: Envelope) -> bool {
+    a.0 <= b.2 && a.2 >= b.0 && a.1 <= b.3 && a.3 >= b.1
+}
+
+/// Fanout of each R-tree node. 16 is a common default for STR-packed trees:
+/// large enough to keep the tree shallow, small enough that a node's
+/// envelope stays a reasonably tight fit.
+const NODE_FANOUT: usize = 16;
+
+enum RTreeNode {
+    Leaf { envelope: Envelope, index: usize },
+    Branch { envelope: Envelope, children: Vec<RTreeNode> },
+}
+
+impl RTreeNode {
+    fn envelope(&self) -> Envelope {
+        match self {
+            Self::Leaf { envelope, .. } | Self::Branch { envelope, .. } => *envelope,
+        }
+    }
+
+    fn query(&self, target: Envelope, out: &mut Vec<usize>) {
+        if !envelopes_intersect(self.envelope(), target) {
+            return;
+        }
+        match self {
+            Self::Leaf { index, .. } => out.push(*index),
+            Self::Branch { children, .. } => {
+                for child in children {
+                    child.query(target, out);
+                }
+            }
+        }
+    }
+}
+
+/// A bulk-loaded R-tree over the bounding boxes of one side of a spatial
+/// join, built with the Sort-Tile-Recursive (STR) algorithm: entries are
+/// sorted by x into `ceil(sqrt(n / M))` vertical slices, each slice is
+/// sorted by y and cut into leaves of `M` entries, and parent levels are
+/// built the same way, bottom-up, until a single root remains.
+pub struct RTree {
+    root: RTreeNode,
+}
+
+impl RTree {
+    pub fn build(envelopes: &[(usize, Envelope)]) -> Option<Self> {
+        let leaves: Vec<RTreeNode> = envelopes
+            .iter()
+            .map(|(index, envelope)| RTreeNode::Leaf {
+                envelope: *envelope,
+                index: *index,
+            })
+            .collect();
+        let root = Self::str_pack(leaves)?;
+        Some(Self { root })
+    }
+
+    fn str_pack(mut nodes: Vec<RTreeNode>) -> Option<RTreeNode> {
+        if nodes.is_empty() {
+            return None;
+        }
+        while nodes.len() > 1 {
+            let n = nodes.len();
+            let num_slices = ((n as f64 / NODE_FANOUT as f64).sqrt().ceil() as usize).max(1);
+            let slice_size = n.div_ceil(num_slices);
+
+            nodes.sort_by(|a, b| {
+                let (ax, _, _, _) = a.envelope();
+                let (bx, _, _, _) = b.envelope();
+                ax.total_cmp(&bx)
+            });
+
+            let mut next_level = Vec::with_capacity(n.div_ceil(NODE_FANOUT));
+            for slice in nodes.chunks_mut(slice_size) {
+                slice.sort_by(|a, b| {
+                    let (_, ay, _, _) = a.envelope();
+                    let (_, by, _, _) = b.envelope();
+                    ay.total_cmp(&by)
+                });
+                for group in slice.chunks_mut(NODE_FANOUT) {
+                    let children: Vec<RTreeNode> = group.drain(..).collect();
+                    let envelope = children
+                        .iter()
+                        .map(RTreeNode::envelope)
+                        .reduce(|a, b| {
+                            (a.0.min(b.0), a.1.min(b.1), a.2.max(b.2), a.3.max(b.3))
+                        })
+                        .unwrap();
+                    next_level.push(RTreeNode::Branch { envelope, children });
+                }
+            }
+            nodes = next_level;
+        }
+        nodes.pop()
+    }
+
+    fn query(&self, target: Envelope) -> Vec<usize> {
+        let mut out = Vec::new();
+        self.root.query(target, &mut out);
+        out
+    }
+}
+
+/// Result of a spatial join: parallel arrays of matched row indices, one
+/// pair per `(build_index, probe_index)` that satisfies `predicate`.
+pub struct SpatialJoinIndices {
+    pub build_indices: Vec<u64>,
+    pub probe_indices: Vec<u64>,
+}
+
+fn predicate_fn(op: &GeoOperation) -> DaftResult<fn(&Geometry, &Geometry) -> bool> {
+    match op {
+        GeoOperation::Intersects => Ok(|l, r| l.intersects(r)),
+        GeoOperation::Contains => Ok(|l, r| l.contains(r)),
+        other => Err(common_error::DaftError::ValueError(format!(
+            "spatial join does not support predicate {:?}",
+            other
+        ))),
+    }
+}
+
+/// Spatially join `build` against `probe`: bulk-load an STR R-tree over
+/// `build`'s bounding boxes, then for each geometry in `probe` query the
+/// tree for candidate overlaps and confirm them with the exact `predicate`.
+/// This is roughly `O((n + m) * log n)` instead of the `O(n * m)` cost of
+/// zipping the two sides element-wise.
+pub fn geo_spatial_join(
+    build: &daft_core::series::Series,
+    probe: &daft_core::series::Series,
+    predicate: GeoOperation,
+) -> DaftResult<SpatialJoinIndices> {
+    let build_array = build.geometry()?;
+    let probe_array = probe.geometry()?;
+    let matches = predicate_fn(&predicate)?;
+
+    let build_geoms: Vec<Option<Geometry>> = GeometryArrayIter::new(build_array).collect();
+    let envelopes: Vec<(usize, Envelope)> = build_geoms
+        .iter()
+        .enumerate()
+        .filter_map(|(i, g)| Some((i, envelope(g.as_ref()?)?)))
+        .collect();
+
+    let mut build_indices = Vec::new();
+    let mut probe_indices = Vec::new();
+
+    let Some(tree) = RTree::build(&envelopes) else {
+        return Ok(SpatialJoinIndices {
+            build_indices,
+            probe_indices,
+        });
+    };
+
+    for (probe_idx, probe_geom) in GeometryArrayIter::new(probe_array).enumerate() {
+        let Some(probe_geom) = probe_geom else {
+            continue;
+        };
+        let Some(probe_envelope) = envelope(&probe_geom) else {
+            continue;
+        };
+        for build_idx in tree.query(probe_envelope) {
+            let build_geom = build_geoms[build_idx].as_ref().unwrap();
+            if matches(build_geom, &probe_geom) {
+                build_indices.push(build_idx as u64);
+                probe_indices.push(probe_idx as u64);
+            }
+        }
+    }
+
+    Ok(SpatialJoinIndices {
+        build_indices,
+        probe_indices,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn box_at(i: usize, x: f64, y: f64) -> (usize, Envelope) {
+        (i, (x, y, x + 1.0, y + 1.0))
+    }
+
+    #[test]
+    fn query_finds_only_overlapping_envelopes() {
+        // A grid of 40 non-overlapping unit boxes, spread out enough to
+        // force the STR bulk load through more than one internal level.
+        let envelopes: Vec<(usize, Envelope)> = (0..40)
+            .map(|i| box_at(i, (i * 10) as f64, (i % 3) as f64 * 10.0))
+            .collect();
+        let tree = RTree::build(&envelopes).unwrap();
+
+        // A probe box that only overlaps entry 7's envelope.
+        let (_, target) = box_at(7, 70.0, (7 % 3) as f64 * 10.0);
+        let hits = tree.query(target);
+        assert_eq!(hits, vec![7]);
+
+        // A probe box far away from every entry finds nothing.
+        assert!(tree.query((10_000.0, 10_000.0, 10_001.0, 10_001.0)).is_empty());
+    }
+
+    #[test]
+    fn query_finds_all_overlaps_at_a_shared_corner() {
+        let envelopes = vec![
+            (0, (0.0, 0.0, 2.0, 2.0)),
+            (1, (1.0, 1.0, 3.0, 3.0)),
+            (2, (5.0, 5.0, 6.0, 6.0)),
+        ];
+        let tree = RTree::build(&envelopes).unwrap();
+
+        let mut hits = tree.query((1.5, 1.5, 1.6, 1.6));
+        hits.sort_unstable();
+        assert_eq!(hits, vec![0, 1]);
+    }
+
+    #[test]
+    fn build_on_empty_input_returns_none() {
+        assert!(RTree::build(&[]).is_none());
+    }
+}
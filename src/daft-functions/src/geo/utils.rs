@@ -8,13 +8,19 @@ use daft_core::{
     prelude::{BinaryArray, DataType, Field},
     series::{IntoSeries, Series},
 };
-use geo::{Area, BooleanOps, Contains, ConvexHull, EuclideanDistance, Geometry, Intersects};
+use geo::{
+    Area, BooleanOps, BoundingRect, Buffer, Centroid, Contains, ConvexHull, EuclideanDistance,
+    GeodesicDistance, Geometry, HaversineDistance, Intersects, MultiPolygon, Simplify,
+};
 use geozero::{wkb, wkt, CoordDimensions, ToGeo, ToWkb, ToWkt};
+use proj::Proj;
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+// `Eq`/`Hash` were dropped once `Simplify`/`Buffer` introduced `f64`
+// payloads, which don't implement either.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[cfg_attr(feature = "python", pyclass(module = "daft.daft"))]
 pub enum GeoOperation {
     Area,
@@ -23,6 +29,126 @@ pub enum GeoOperation {
     Intersects,
     Intersection,
     Contains,
+    /// Reproject every geometry from its current CRS (read off the input
+    /// `Series`' field metadata) to `to_crs`.
+    Transform { to_crs: String },
+    /// Great-circle distance between geometry centroids, in meters. Correct
+    /// for lon/lat (EPSG:4326) data, unlike `Distance`.
+    HaversineDistance,
+    /// Geodesic (ellipsoidal) distance between geometry centroids, in
+    /// meters. More accurate than `HaversineDistance` over long ranges.
+    GeodesicDistance,
+    Union,
+    Difference,
+    SymmetricDifference,
+    Centroid,
+    /// Axis-aligned bounding box of each geometry, as a `Polygon`.
+    BoundingBox,
+    /// Douglas-Peucker simplification with the given tolerance.
+    Simplify { epsilon: f64 },
+    Buffer { distance: f64 },
+}
+
+/// The metadata key under which a geometry `Field`'s coordinate reference
+/// system is stored, e.g. an authority code (`"EPSG:4326"`) or a PROJJSON
+/// string.
+pub const CRS_METADATA_KEY: &str = "crs";
+
+/// Read the CRS, if any, off a `GeometryArray`'s field metadata.
+pub fn geometry_crs(geo: &GeometryArray) -> Option<String> {
+    geo.field.metadata.get(CRS_METADATA_KEY).cloned()
+}
+
+/// The metadata key under which a geometry `Field`'s coordinate
+/// dimensionality (XY/XYZ/XYM/XYZM) is stored.
+pub const COORD_DIMS_METADATA_KEY: &str = "coord_dims";
+
+/// Which coordinate dimensions a geometry carries. Planar operations
+/// (`Area`, `Distance`, ...) always operate on the `Xy` projection, but the
+/// decode/encode paths use this to avoid silently flattening 3D/measured
+/// geometries loaded from WKB/WKT.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoordDimTag {
+    Xy,
+    Xyz,
+    Xym,
+    Xyzm,
+}
+
+impl CoordDimTag {
+    fn as_metadata_str(self) -> &'static str {
+        match self {
+            Self::Xy => "xy",
+            Self::Xyz => "xyz",
+            Self::Xym => "xym",
+            Self::Xyzm => "xyzm",
+        }
+    }
+
+    fn from_metadata_str(s: &str) -> Self {
+        match s {
+            "xyz" => Self::Xyz,
+            "xym" => Self::Xym,
+            "xyzm" => Self::Xyzm,
+            _ => Self::Xy,
+        }
+    }
+
+    pub fn to_coord_dimensions(self) -> CoordDimensions {
+        match self {
+            Self::Xy => CoordDimensions::xy(),
+            Self::Xyz => CoordDimensions::xyz(),
+            Self::Xym => CoordDimensions::xym(),
+            Self::Xyzm => CoordDimensions::xyzm(),
+        }
+    }
+}
+
+/// Read the coordinate dimensionality off a `GeometryArray`'s field
+/// metadata, defaulting to `Xy` for arrays that don't carry the tag (e.g.
+/// the output of a planar operation).
+pub fn geometry_coord_dims(geo: &GeometryArray) -> CoordDimTag {
+    geo.field
+        .metadata
+        .get(COORD_DIMS_METADATA_KEY)
+        .map(|s| CoordDimTag::from_metadata_str(s))
+        .unwrap_or(CoordDimTag::Xy)
+}
+
+/// Detect the coordinate dimensionality of a (SRID-stripped) WKB geometry
+/// from its type word: PostGIS sets the high Z/M bits directly, while
+/// plain ISO WKB instead adds 1000/2000/3000 to the base geometry type.
+fn wkb_coord_dims(type_word: u32) -> CoordDimTag {
+    const Z_FLAG: u32 = 0x8000_0000;
+    const M_FLAG: u32 = 0x4000_0000;
+    match (type_word & Z_FLAG != 0, type_word & M_FLAG != 0) {
+        (true, true) => CoordDimTag::Xyzm,
+        (true, false) => CoordDimTag::Xyz,
+        (false, true) => CoordDimTag::Xym,
+        (false, false) => match (type_word % 4000) / 1000 {
+            1 => CoordDimTag::Xyz,
+            2 => CoordDimTag::Xym,
+            3 => CoordDimTag::Xyzm,
+            _ => CoordDimTag::Xy,
+        },
+    }
+}
+
+/// Detect the coordinate dimensionality of a WKT geometry from its `Z`/`M`/
+/// `ZM` tag, e.g. `"POINT Z (1 2 3)"`. The tag is always its own whitespace
+/// token right after the geometry keyword, so a plain substring search is
+/// wrong: it false-positives on any type name that merely contains the
+/// letter M, e.g. `MULTIPOINT`, `MULTIPOLYGON`, `GEOMETRYCOLLECTION`.
+fn wkt_coord_dims(text: &str) -> CoordDimTag {
+    let header = text.split('(').next().unwrap_or(text).to_uppercase();
+    let mut tokens = header.split_whitespace();
+    tokens.next(); // geometry keyword, e.g. "POINT"
+    match tokens.next() {
+        Some("ZM") => CoordDimTag::Xyzm,
+        Some("Z") => CoordDimTag::Xyz,
+        Some("M") => CoordDimTag::Xym,
+        _ => CoordDimTag::Xy,
+    }
 }
 
 pub struct GeometryArrayIter<'a> {
@@ -59,24 +185,43 @@ impl<'a> Iterator for GeometryArrayIter<'a> {
     }
 }
 
-struct GH {
+pub(crate) struct GH {
     geo_vec: Vec<u8>,
     offsets: Vec<i64>,
     validity: arrow2::bitmap::MutableBitmap,
+    crs: Option<String>,
+    coord_dims: CoordDimTag,
 }
 
 impl GH {
-    fn new(capacity: usize) -> Self {
+    pub(crate) fn new(capacity: usize) -> Self {
         let mut x = Self {
             geo_vec: Vec::with_capacity(capacity),
             offsets: Vec::with_capacity(capacity + 1),
             validity: arrow2::bitmap::MutableBitmap::with_capacity(capacity),
+            crs: None,
+            coord_dims: CoordDimTag::Xy,
         };
         x.offsets.push(0i64);
         x
     }
 
-    fn push(&mut self, geo: Geometry) {
+    /// Tag the geometries this `GH` accumulates with a CRS, so it round
+    /// trips onto the resulting `GeometryArray`'s field metadata.
+    pub(crate) fn with_crs(mut self, crs: Option<String>) -> Self {
+        self.crs = crs;
+        self
+    }
+
+    /// Tag the geometries this `GH` accumulates with a coordinate
+    /// dimensionality, so `push_raw` callers that preserve Z/M through can
+    /// advertise it on the resulting `GeometryArray`.
+    pub(crate) fn with_coord_dims(mut self, coord_dims: CoordDimTag) -> Self {
+        self.coord_dims = coord_dims;
+        self
+    }
+
+    pub(crate) fn push(&mut self, geo: Geometry) {
         let geo_bytes = geo.to_wkb(CoordDimensions::xy()).unwrap();
         self.geo_vec.extend(geo_bytes.iter());
         self.offsets
@@ -84,12 +229,22 @@ impl GH {
         self.validity.push(true);
     }
 
-    fn null(&mut self) {
+    /// Append already-encoded WKB bytes verbatim, bypassing the
+    /// `geo::Geometry` round trip that `push` does (and which would
+    /// flatten any Z/M coordinates to 2D).
+    pub(crate) fn push_raw(&mut self, bytes: &[u8]) {
+        self.geo_vec.extend_from_slice(bytes);
+        self.offsets
+            .push(self.offsets.last().unwrap() + bytes.len() as i64);
+        self.validity.push(true);
+    }
+
+    pub(crate) fn null(&mut self) {
         self.offsets.push(*self.offsets.last().unwrap());
         self.validity.push(false);
     }
 
-    fn into_series(self, name: &str) -> DaftResult<Series> {
+    pub(crate) fn into_series(self, name: &str) -> DaftResult<Series> {
         gh_to(name, self)
     }
 }
@@ -105,7 +260,92 @@ fn gh_to(name: &str, g: GH) -> DaftResult<Series> {
         arrow2::offset::OffsetsBuffer::try_from(g.offsets)?,
         g.validity.into(),
     );
-    Ok(GeometryArray::new(Field::new(name, DataType::Geometry), data_array).into_series())
+    let mut metadata = std::collections::BTreeMap::new();
+    if let Some(crs) = g.crs {
+        metadata.insert(CRS_METADATA_KEY.to_string(), crs);
+    }
+    if g.coord_dims != CoordDimTag::Xy {
+        metadata.insert(
+            COORD_DIMS_METADATA_KEY.to_string(),
+            g.coord_dims.as_metadata_str().to_string(),
+        );
+    }
+    let mut field = Field::new(name, DataType::Geometry);
+    if !metadata.is_empty() {
+        field = field.with_metadata(metadata);
+    }
+    Ok(GeometryArray::new(field, data_array).into_series())
+}
+
+/// The bit (`0x2000_0000`) of the geometry-type word in an EWKB header that, when set,
+/// indicates a 4-byte SRID follows it (the PostGIS EWKB extension).
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// Convert an authority-code CRS string (`"EPSG:4326"`) to its numeric SRID.
+fn crs_to_srid(crs: &str) -> Option<u32> {
+    crs.strip_prefix("EPSG:")?.parse().ok()
+}
+
+fn srid_to_crs(srid: u32) -> String {
+    format!("EPSG:{srid}")
+}
+
+/// Read the 4-byte geometry-type word out of a (SRID-stripped) WKB buffer.
+fn wkb_type_word(bytes: &[u8]) -> u32 {
+    if bytes.len() < 5 {
+        return 0;
+    }
+    if bytes[0] == 1 {
+        u32::from_le_bytes(bytes[1..5].try_into().unwrap())
+    } else {
+        u32::from_be_bytes(bytes[1..5].try_into().unwrap())
+    }
+}
+
+/// Strip a PostGIS EWKB SRID prefix, if present, returning plain WKB bytes
+/// geozero's `wkb::Wkb` parser understands plus the SRID it carried.
+fn strip_ewkb_srid(bytes: &[u8]) -> DaftResult<(Vec<u8>, Option<u32>)> {
+    if bytes.len() < 5 {
+        return Ok((bytes.to_vec(), None));
+    }
+    let little_endian = bytes[0] == 1;
+    let read_u32 = |b: &[u8]| {
+        if little_endian {
+            u32::from_le_bytes(b.try_into().unwrap())
+        } else {
+            u32::from_be_bytes(b.try_into().unwrap())
+        }
+    };
+    let write_u32 = |v: u32| {
+        if little_endian {
+            v.to_le_bytes()
+        } else {
+            v.to_be_bytes()
+        }
+    };
+    let geom_type = read_u32(&bytes[1..5]);
+    if geom_type & EWKB_SRID_FLAG == 0 {
+        return Ok((bytes.to_vec(), None));
+    }
+    if bytes.len() < 9 {
+        return Err(DaftError::ValueError(
+            "Truncated EWKB: missing SRID".to_string(),
+        ));
+    }
+    let srid = read_u32(&bytes[5..9]);
+    let mut plain = Vec::with_capacity(bytes.len() - 4);
+    plain.push(bytes[0]);
+    plain.extend_from_slice(&write_u32(geom_type & !EWKB_SRID_FLAG));
+    plain.extend_from_slice(&bytes[9..]);
+    Ok((plain, Some(srid)))
+}
+
+/// Split an EWKT `SRID=4326;POINT(1 2)` prefix off plain WKT.
+fn strip_ewkt_srid(text: &str) -> (&str, Option<u32>) {
+    match text.strip_prefix("SRID=").and_then(|rest| rest.split_once(';')) {
+        Some((srid, wkt)) => (wkt, srid.parse().ok()),
+        None => (text, None),
+    }
 }
 
 pub fn decode_series(s: &Series, raise_error_on_failure: bool) -> DaftResult<Series> {
@@ -118,27 +358,48 @@ pub fn decode_series(s: &Series, raise_error_on_failure: bool) -> DaftResult<Ser
                 .downcast_ref::<arrow2::array::BinaryArray<i64>>()
                 .unwrap();
             let mut gh = GH::new(arrow_array.len());
+            let mut srid = None;
+            let mut coord_dims = CoordDimTag::Xy;
             for bytes in arrow_array.iter() {
                 match bytes {
-                    Some(bytes) => match wkb::Wkb(bytes).to_geo() {
-                        Ok(geo) => gh.push(geo),
-                        Err(_) => {
-                            if raise_error_on_failure {
-                                return Err(DaftError::ValueError(
-                                    "Could not decode WKB".to_string(),
-                                ));
+                    Some(bytes) => {
+                        let (plain, row_srid) = strip_ewkb_srid(bytes)?;
+                        srid = srid.or(row_srid);
+                        let row_dims = wkb_coord_dims(wkb_type_word(&plain));
+                        match wkb::Wkb(plain.as_slice()).to_geo() {
+                            Ok(geo) => {
+                                if row_dims == CoordDimTag::Xy {
+                                    gh.push(geo);
+                                } else {
+                                    // `geo::Geometry` is 2D-only: keep the
+                                    // original WKB bytes verbatim so the
+                                    // Z/M coordinates survive decode/encode.
+                                    coord_dims = row_dims;
+                                    gh.push_raw(&plain);
+                                }
+                            }
+                            Err(_) => {
+                                if raise_error_on_failure {
+                                    return Err(DaftError::ValueError(
+                                        "Could not decode WKB".to_string(),
+                                    ));
+                                }
+                                gh.null()
                             }
-                            gh.null()
                         }
-                    },
+                    }
                     None => gh.null(),
                 }
             }
-            gh.into_series(binary.name())
+            gh.with_crs(srid.map(srid_to_crs))
+                .with_coord_dims(coord_dims)
+                .into_series(binary.name())
         }
         DataType::Utf8 => {
             let strings = s.utf8()?;
             let mut gh = GH::new(strings.len());
+            let mut srid = None;
+            let mut coord_dims = CoordDimTag::Xy;
             let s = strings
                 .data()
                 .as_any()
@@ -146,22 +407,50 @@ pub fn decode_series(s: &Series, raise_error_on_failure: bool) -> DaftResult<Ser
                 .unwrap();
             for x in s.iter() {
                 match x {
-                    Some(x) => match wkt::Wkt(x).to_geo() {
-                        Ok(geo) => gh.push(geo),
-                        Err(_) => {
-                            if raise_error_on_failure {
-                                return Err(DaftError::ValueError(format!(
-                                    "Could not decode WKT text {}",
-                                    x
-                                )));
+                    Some(x) => {
+                        let (plain, row_srid) = strip_ewkt_srid(x);
+                        srid = srid.or(row_srid);
+                        let row_dims = wkt_coord_dims(plain);
+                        if row_dims == CoordDimTag::Xy {
+                            match wkt::Wkt(plain).to_geo() {
+                                Ok(geo) => gh.push(geo),
+                                Err(_) => {
+                                    if raise_error_on_failure {
+                                        return Err(DaftError::ValueError(format!(
+                                            "Could not decode WKT text {}",
+                                            x
+                                        )));
+                                    }
+                                    gh.null();
+                                }
+                            }
+                        } else {
+                            // Transcode straight to WKB at the detected
+                            // dimensionality so Z/M values aren't flattened
+                            // by a round trip through 2D `geo::Geometry`.
+                            match wkt::Wkt(plain).to_wkb(row_dims.to_coord_dimensions()) {
+                                Ok(bytes) => {
+                                    coord_dims = row_dims;
+                                    gh.push_raw(&bytes);
+                                }
+                                Err(_) => {
+                                    if raise_error_on_failure {
+                                        return Err(DaftError::ValueError(format!(
+                                            "Could not decode WKT text {}",
+                                            x
+                                        )));
+                                    }
+                                    gh.null();
+                                }
                             }
-                            gh.null();
                         }
-                    },
+                    }
                     None => gh.null(),
                 }
             }
-            gh.into_series(strings.name())
+            gh.with_crs(srid.map(srid_to_crs))
+                .with_coord_dims(coord_dims)
+                .into_series(strings.name())
         }
         other => Err(DaftError::TypeError(format!(
             "GeoDecode can only decode Binary or Utf8 arrays, got {}",
@@ -170,50 +459,158 @@ pub fn decode_series(s: &Series, raise_error_on_failure: bool) -> DaftResult<Ser
     }
 }
 
-pub fn to_wkt(s: &Series) -> DaftResult<Series> {
+/// Render each geometry in `s` as WKT, or as EWKT (`SRID=...;...`) when
+/// `extended` is set and the series carries a CRS.
+pub fn to_wkt(s: &Series, extended: bool) -> DaftResult<Series> {
     let geo = s.geometry()?;
+    let crs = geometry_crs(geo);
+    let coord_dims = geometry_coord_dims(geo);
+    let srid = if extended {
+        crs.as_deref().and_then(crs_to_srid)
+    } else {
+        None
+    };
     let mut wkt_vec: Vec<Option<String>> = Vec::with_capacity(geo.len());
-    for g in GeometryArrayIter::new(geo) {
-        match g {
-            Some(g) => {
-                let wkt = g.to_wkt().unwrap();
-                wkt_vec.push(Some(wkt));
+    for i in 0..geo.len() {
+        let raw = geo.physical.get(i);
+        let wkt = match raw {
+            Some(raw) => {
+                let bytes = raw.u8().unwrap().as_slice();
+                // Go straight from the stored WKB bytes to WKT so any
+                // Z/M coordinates survive; `GeometryArrayIter` would force
+                // a lossy round trip through 2D `geo::Geometry`.
+                let wkt = if coord_dims == CoordDimTag::Xy {
+                    wkb::Wkb(bytes).to_geo().unwrap().to_wkt().unwrap()
+                } else {
+                    wkb::Wkb(bytes).to_wkt().unwrap()
+                };
+                let wkt = match srid {
+                    Some(srid) => format!("SRID={srid};{wkt}"),
+                    None => wkt,
+                };
+                Some(wkt)
             }
-            None => wkt_vec.push(None),
-        }
+            None => None,
+        };
+        wkt_vec.push(wkt);
     }
     let utf8_array = arrow2::array::Utf8Array::<i64>::from(wkt_vec);
-    Series::from_arrow(
-        Arc::new(Field::new(geo.name(), DataType::Utf8)),
-        Box::new(utf8_array),
-    )
+    let mut field = Field::new(geo.name(), DataType::Utf8);
+    if let Some(crs) = crs {
+        field = field.with_metadata([(CRS_METADATA_KEY.to_string(), crs)].into());
+    }
+    Series::from_arrow(Arc::new(field), Box::new(utf8_array))
 }
 
-pub fn to_wkb(s: &Series) -> DaftResult<Series> {
+/// Render each geometry in `s` as WKB, or as EWKB (SRID embedded in the
+/// geometry-type word) when `extended` is set and the series carries a CRS.
+pub fn to_wkb(s: &Series, extended: bool) -> DaftResult<Series> {
     let geo = s.geometry()?;
+    let crs = geometry_crs(geo);
+    let coord_dims = geometry_coord_dims(geo);
+    let srid = if extended {
+        crs.as_deref().and_then(crs_to_srid)
+    } else {
+        None
+    };
     let mut wkb_vec: Vec<Option<Vec<u8>>> = Vec::with_capacity(geo.len());
-    for g in GeometryArrayIter::new(geo) {
-        match g {
-            Some(g) => {
-                let wkb = g.to_wkb(CoordDimensions::xy()).unwrap();
-                wkb_vec.push(Some(wkb));
+    for i in 0..geo.len() {
+        let raw = geo.physical.get(i);
+        let wkb = match raw {
+            Some(raw) => {
+                let bytes = raw.u8().unwrap().as_slice();
+                // Non-planar geometries are already stored as the raw WKB
+                // bytes decode produced (see `decode_series`), so just copy
+                // them through rather than re-encoding via 2D
+                // `geo::Geometry`, which would flatten Z/M.
+                let wkb = if coord_dims == CoordDimTag::Xy {
+                    wkb::Wkb(bytes)
+                        .to_geo()
+                        .unwrap()
+                        .to_wkb(CoordDimensions::xy())
+                        .unwrap()
+                } else {
+                    bytes.to_vec()
+                };
+                let wkb = match srid {
+                    Some(srid) => add_ewkb_srid(wkb, srid, coord_dims),
+                    None => wkb,
+                };
+                Some(wkb)
             }
-            None => wkb_vec.push(None),
-        }
+            None => None,
+        };
+        wkb_vec.push(wkb);
     }
     let bin_array = arrow2::array::BinaryArray::<i64>::from(wkb_vec);
-    Ok(BinaryArray::new(
-        Arc::new(Field::new(geo.name(), DataType::Binary)),
-        Box::new(bin_array),
-    )
-    .unwrap()
-    .into_series())
+    let mut field = Field::new(geo.name(), DataType::Binary);
+    if let Some(crs) = crs {
+        field = field.with_metadata([(CRS_METADATA_KEY.to_string(), crs)].into());
+    }
+    Ok(BinaryArray::new(Arc::new(field), Box::new(bin_array))
+        .unwrap()
+        .into_series())
 }
 
-pub fn encode_series(s: &Series, text: bool) -> DaftResult<Series> {
+/// Normalize a WKB geometry-type word to PostGIS's Z/M bit-flag form
+/// (`Z_FLAG`/`M_FLAG` set on a plain 1-7 base type), regardless of whether it
+/// arrived in that form already or as ISO-extended (`+1000`/`+2000`/`+3000`,
+/// as produced by the WKT-decode transcode path and the shapefile reader).
+/// `add_ewkb_srid` needs this because it can't tell which convention a given
+/// row's bytes are in just by OR-ing a flag on: splicing the SRID flag onto
+/// an ISO-extended type word (instead of a bit-flag one) produces a type
+/// word no EWKB reader recognizes.
+fn normalize_wkb_type_word(type_word: u32, dims: CoordDimTag) -> u32 {
+    const Z_FLAG: u32 = 0x8000_0000;
+    const M_FLAG: u32 = 0x4000_0000;
+    let base_type = if type_word & (Z_FLAG | M_FLAG) != 0 {
+        type_word & !(Z_FLAG | M_FLAG)
+    } else {
+        type_word % 1000
+    };
+    match dims {
+        CoordDimTag::Xy => base_type,
+        CoordDimTag::Xyz => base_type | Z_FLAG,
+        CoordDimTag::Xym => base_type | M_FLAG,
+        CoordDimTag::Xyzm => base_type | Z_FLAG | M_FLAG,
+    }
+}
+
+/// Set the EWKB SRID flag on a plain-WKB geometry-type word and splice the
+/// SRID in right after it, mirroring what PostGIS emits. The type word is
+/// normalized to bit-flag form first (see `normalize_wkb_type_word`) since
+/// `dims` is the only reliable source of truth for it -- the raw bytes may
+/// be ISO-extended rather than already carrying the Z/M bit flags.
+fn add_ewkb_srid(wkb: Vec<u8>, srid: u32, dims: CoordDimTag) -> Vec<u8> {
+    let little_endian = wkb[0] == 1;
+    let stored_type = if little_endian {
+        u32::from_le_bytes(wkb[1..5].try_into().unwrap())
+    } else {
+        u32::from_be_bytes(wkb[1..5].try_into().unwrap())
+    };
+    let geom_type = normalize_wkb_type_word(stored_type, dims) | EWKB_SRID_FLAG;
+    let srid_bytes = if little_endian {
+        srid.to_le_bytes()
+    } else {
+        srid.to_be_bytes()
+    };
+    let geom_type_bytes = if little_endian {
+        geom_type.to_le_bytes()
+    } else {
+        geom_type.to_be_bytes()
+    };
+    let mut out = Vec::with_capacity(wkb.len() + 4);
+    out.push(wkb[0]);
+    out.extend_from_slice(&geom_type_bytes);
+    out.extend_from_slice(&srid_bytes);
+    out.extend_from_slice(&wkb[5..]);
+    out
+}
+
+pub fn encode_series(s: &Series, text: bool, extended: bool) -> DaftResult<Series> {
     match text {
-        true => to_wkt(s),
-        false => to_wkb(s),
+        true => to_wkt(s, extended),
+        false => to_wkb(s, extended),
     }
 }
 
@@ -221,28 +618,119 @@ pub fn geo_unary_dispatch(s: &Series, op: GeoOperation) -> DaftResult<Series> {
     match op {
         GeoOperation::Area => geo_unary_to_scalar::<f64, _>(s, |g| g.unsigned_area()),
         GeoOperation::ConvexHull => geo_unary_to_geo(s, |g| g.convex_hull().into()),
+        GeoOperation::Transform { to_crs } => geo_transform(s, &to_crs),
+        GeoOperation::Centroid => geo_unary_to_geo_opt(s, |g| g.centroid().map(Geometry::Point)),
+        GeoOperation::BoundingBox => {
+            geo_unary_to_geo_opt(s, |g| g.bounding_rect().map(|r| Geometry::Polygon(r.to_polygon())))
+        }
+        GeoOperation::Simplify { epsilon } => {
+            geo_unary_to_geo(s, move |g| simplify_geometry(g, epsilon))
+        }
+        GeoOperation::Buffer { distance } => {
+            geo_unary_to_geo(s, move |g| buffer_geometry(g, distance))
+        }
         _ => Err(DaftError::ValueError(format!("unsupported op {:?}", op))),
     }
 }
 
+fn simplify_geometry(geo: Geometry, epsilon: f64) -> Geometry {
+    match geo {
+        Geometry::LineString(g) => Geometry::LineString(g.simplify(&epsilon)),
+        Geometry::Polygon(g) => Geometry::Polygon(g.simplify(&epsilon)),
+        Geometry::MultiLineString(g) => Geometry::MultiLineString(g.simplify(&epsilon)),
+        Geometry::MultiPolygon(g) => Geometry::MultiPolygon(g.simplify(&epsilon)),
+        other => other,
+    }
+}
+
+fn buffer_geometry(geo: Geometry, distance: f64) -> Geometry {
+    match geo {
+        Geometry::Point(g) => Geometry::Polygon(g.buffer(distance)),
+        Geometry::LineString(g) => Geometry::Polygon(g.buffer(distance)),
+        Geometry::Polygon(g) => Geometry::Polygon(g.buffer(distance)),
+        Geometry::MultiPoint(g) => Geometry::MultiPolygon(g.buffer(distance)),
+        Geometry::MultiLineString(g) => Geometry::MultiPolygon(g.buffer(distance)),
+        Geometry::MultiPolygon(g) => Geometry::MultiPolygon(g.buffer(distance)),
+        other => other,
+    }
+}
+
+/// Promote a Polygon to a single-member MultiPolygon so mixed
+/// Polygon/MultiPolygon pairs can be combined by the set operations below
+/// instead of being rejected.
+fn as_multi_polygon(geo: Geometry) -> Option<MultiPolygon> {
+    match geo {
+        Geometry::Polygon(p) => Some(MultiPolygon::new(vec![p])),
+        Geometry::MultiPolygon(mp) => Some(mp),
+        _ => None,
+    }
+}
+
 pub fn geo_binary_dispatch(lhs: &Series, rhs: &Series, op: GeoOperation) -> DaftResult<Series> {
     match op {
         GeoOperation::Distance => {
             geo_binary_to_scalar::<f64, _>(lhs, rhs, |l, r| l.euclidean_distance(&r))
         }
+        GeoOperation::HaversineDistance => geo_binary_to_scalar::<f64, _>(lhs, rhs, |l, r| {
+            let (lc, rc) = (l.centroid(), r.centroid());
+            match (lc, rc) {
+                (Some(lc), Some(rc)) => lc.haversine_distance(&rc),
+                _ => f64::NAN,
+            }
+        }),
+        GeoOperation::GeodesicDistance => geo_binary_to_scalar::<f64, _>(lhs, rhs, |l, r| {
+            let (lc, rc) = (l.centroid(), r.centroid());
+            match (lc, rc) {
+                (Some(lc), Some(rc)) => lc.geodesic_distance(&rc),
+                _ => f64::NAN,
+            }
+        }),
         GeoOperation::Intersects => geo_binary_to_bool(lhs, rhs, |l, r| l.intersects(&r)),
         GeoOperation::Contains => geo_binary_to_bool(lhs, rhs, |l, r| l.contains(&r)),
-        GeoOperation::Intersection => geo_binary_to_geo(lhs, rhs, |l, r| match (l, r) {
-            (Geometry::Polygon(l), Geometry::Polygon(r)) => Some(l.intersection(&r).into()),
-            (Geometry::MultiPolygon(l), Geometry::MultiPolygon(r)) => {
-                Some(l.intersection(&r).into())
-            }
-            _ => None,
+        GeoOperation::Intersection => geo_binary_to_geo(lhs, rhs, |l, r| {
+            let (l, r) = (as_multi_polygon(l)?, as_multi_polygon(r)?);
+            Some(l.intersection(&r).into())
+        }),
+        GeoOperation::Union => geo_binary_to_geo(lhs, rhs, |l, r| {
+            let (l, r) = (as_multi_polygon(l)?, as_multi_polygon(r)?);
+            Some(l.union(&r).into())
+        }),
+        GeoOperation::Difference => geo_binary_to_geo(lhs, rhs, |l, r| {
+            let (l, r) = (as_multi_polygon(l)?, as_multi_polygon(r)?);
+            Some(l.difference(&r).into())
+        }),
+        GeoOperation::SymmetricDifference => geo_binary_to_geo(lhs, rhs, |l, r| {
+            let (l, r) = (as_multi_polygon(l)?, as_multi_polygon(r)?);
+            Some(l.xor(&r).into())
         }),
         _ => Err(DaftError::ValueError(format!("unsupported op {:?}", op))),
     }
 }
 
+/// Reproject every geometry in `s` from its current CRS to `to_crs`,
+/// tagging the result with `to_crs` so it keeps round tripping correctly.
+pub fn geo_transform(s: &Series, to_crs: &str) -> DaftResult<Series> {
+    let geo_array = s.geometry()?;
+    let from_crs = geometry_crs(geo_array)
+        .ok_or_else(|| DaftError::ValueError("Transform requires a source CRS".to_string()))?;
+    let proj = Proj::new_known_crs(&from_crs, to_crs, None)
+        .map_err(|e| DaftError::ValueError(format!("Could not build CRS transform: {e}")))?;
+    let mut gh = GH::new(geo_array.len()).with_crs(Some(to_crs.to_string()));
+    for geo in GeometryArrayIter::new(geo_array) {
+        match geo {
+            Some(g) => gh.push(transform_geometry(&proj, g)?),
+            None => gh.null(),
+        }
+    }
+    gh.into_series(geo_array.name())
+}
+
+fn transform_geometry(proj: &Proj, geo: Geometry) -> DaftResult<Geometry> {
+    use geo::MapCoords;
+    geo.try_map_coords(|c| proj.convert(c))
+        .map_err(|e| DaftError::ValueError(format!("Could not reproject geometry: {e}")))
+}
+
 pub fn geo_unary_to_scalar<T: NativeType, F>(s: &Series, op_fn: F) -> DaftResult<Series>
 where
     F: Fn(Geometry) -> T,
@@ -264,7 +752,7 @@ where
     F: Fn(Geometry) -> Geometry,
 {
     let geo_array = s.geometry()?;
-    let mut gh = GH::new(geo_array.len());
+    let mut gh = GH::new(geo_array.len()).with_crs(geometry_crs(geo_array));
     for geo in GeometryArrayIter::new(geo_array) {
         match geo {
             Some(g) => gh.push(op_fn(g)),
@@ -274,6 +762,25 @@ where
     gh.into_series(geo_array.name())
 }
 
+/// Like `geo_unary_to_geo`, but for ops that can legitimately have no
+/// result for a given input (e.g. the centroid of an empty geometry) —
+/// `op_fn` returning `None` emits a null row instead of a fabricated
+/// geometry.
+pub fn geo_unary_to_geo_opt<F>(s: &Series, op_fn: F) -> DaftResult<Series>
+where
+    F: Fn(Geometry) -> Option<Geometry>,
+{
+    let geo_array = s.geometry()?;
+    let mut gh = GH::new(geo_array.len()).with_crs(geometry_crs(geo_array));
+    for geo in GeometryArrayIter::new(geo_array) {
+        match geo.and_then(&op_fn) {
+            Some(g) => gh.push(g),
+            None => gh.null(),
+        }
+    }
+    gh.into_series(geo_array.name())
+}
+
 pub fn geo_binary_to_scalar<T: NativeType, F>(
     lhs: &Series,
     rhs: &Series,
@@ -325,7 +832,7 @@ where
 {
     let lhs_array = lhs.geometry()?;
     let rhs_array = rhs.geometry()?;
-    let mut gh = GH::new(lhs_array.len());
+    let mut gh = GH::new(lhs_array.len()).with_crs(geometry_crs(lhs_array));
     for (lhg, rhg) in GeometryArrayIter::new(lhs_array).zip(GeometryArrayIter::new(rhs_array)) {
         match (lhg, rhg) {
             (Some(l), Some(r)) => match op_fn(l, r) {
@@ -337,3 +844,157 @@ where
     }
     gh.into_series(lhs_array.name())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf8_series(values: Vec<Option<&str>>) -> Series {
+        let array = arrow2::array::Utf8Array::<i64>::from(values);
+        Series::from_arrow(Arc::new(Field::new("geom", DataType::Utf8)), Box::new(array)).unwrap()
+    }
+
+    fn utf8_rows(s: &Series) -> Vec<Option<String>> {
+        s.utf8()
+            .unwrap()
+            .data()
+            .as_any()
+            .downcast_ref::<arrow2::array::Utf8Array<i64>>()
+            .unwrap()
+            .iter()
+            .map(|v| v.map(str::to_string))
+            .collect()
+    }
+
+    #[test]
+    fn wkt_coord_dims_ignores_the_letter_m_in_type_names() {
+        // These all contain an 'M' in the type keyword itself but carry no
+        // measure value -- a naive substring search misclassifies them.
+        assert_eq!(wkt_coord_dims("MULTIPOINT (0 0, 1 1)"), CoordDimTag::Xy);
+        assert_eq!(
+            wkt_coord_dims("MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)))"),
+            CoordDimTag::Xy
+        );
+        assert_eq!(
+            wkt_coord_dims("GEOMETRYCOLLECTION (POINT (0 0))"),
+            CoordDimTag::Xy
+        );
+        assert_eq!(wkt_coord_dims("POINT M (1 2 3)"), CoordDimTag::Xym);
+        assert_eq!(wkt_coord_dims("POINT Z (1 2 3)"), CoordDimTag::Xyz);
+        assert_eq!(wkt_coord_dims("POINT ZM (1 2 3 4)"), CoordDimTag::Xyzm);
+    }
+
+    #[test]
+    fn decode_series_round_trips_plain_multi_geometries() {
+        let wkt = utf8_series(vec![
+            Some("MULTIPOLYGON (((0 0, 2 0, 2 2, 0 2, 0 0)))"),
+            Some("GEOMETRYCOLLECTION (POINT (1 1))"),
+        ]);
+        let decoded = decode_series(&wkt, true).unwrap();
+        // A false Xym detection would have pushed these down the raw-WKB
+        // path and tagged the whole array non-planar.
+        assert_eq!(geometry_coord_dims(decoded.geometry().unwrap()), CoordDimTag::Xy);
+
+        let round_tripped = to_wkt(&decoded, false).unwrap();
+        assert_eq!(
+            utf8_rows(&round_tripped),
+            vec![
+                Some("MULTIPOLYGON(((0 0,2 0,2 2,0 2,0 0)))".to_string()),
+                Some("GEOMETRYCOLLECTION(POINT(1 1))".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn ewkt_srid_round_trips_through_decode_and_encode() {
+        let ewkt = utf8_series(vec![Some("SRID=4326;POINT(1 2)")]);
+        let decoded = decode_series(&ewkt, true).unwrap();
+        assert_eq!(
+            geometry_crs(decoded.geometry().unwrap()),
+            Some("EPSG:4326".to_string())
+        );
+
+        let round_tripped = to_wkt(&decoded, true).unwrap();
+        assert_eq!(
+            utf8_rows(&round_tripped),
+            vec![Some("SRID=4326;POINT(1 2)".to_string())]
+        );
+    }
+
+    #[test]
+    fn ewkb_srid_survives_a_geometry_whose_raw_bytes_are_iso_extended_z() {
+        // A WKT Z string, once its SRID prefix is stripped, transcodes straight to raw WKB bytes
+        // (`decode_series`'s push_raw branch) in ISO-extended form (type
+        // `1001`, not the PostGIS bit-flag `0x80000001`). Splicing the EWKB
+        // SRID flag onto that word without normalizing it first used to
+        // produce a type word no EWKB reader recognizes.
+        let ewkt = utf8_series(vec![Some("SRID=4326;POINT Z (1 2 3)")]);
+        let decoded = decode_series(&ewkt, true).unwrap();
+        assert_eq!(geometry_coord_dims(decoded.geometry().unwrap()), CoordDimTag::Xyz);
+
+        let ewkb = to_wkb(&decoded, true).unwrap();
+        let binary = ewkb.binary().unwrap();
+        let bytes = binary
+            .data()
+            .as_any()
+            .downcast_ref::<arrow2::array::BinaryArray<i64>>()
+            .unwrap()
+            .value(0);
+        let type_word = u32::from_le_bytes(bytes[1..5].try_into().unwrap());
+        const Z_FLAG: u32 = 0x8000_0000;
+        assert_eq!(type_word, Z_FLAG | EWKB_SRID_FLAG | 1);
+
+        // And it decodes back losslessly, SRID and Z value intact.
+        let redecoded = decode_series(&ewkb, true).unwrap();
+        assert_eq!(
+            geometry_crs(redecoded.geometry().unwrap()),
+            Some("EPSG:4326".to_string())
+        );
+        let round_tripped = to_wkt(&redecoded, true).unwrap();
+        assert_eq!(
+            utf8_rows(&round_tripped),
+            vec![Some("SRID=4326;POINT Z(1 2 3)".to_string())]
+        );
+    }
+
+    #[test]
+    fn union_of_two_overlapping_polygons_merges_them_into_one() {
+        // Two unit-height-2 squares sharing the full y range and overlapping
+        // over x in [1, 2]: their union is the single x:[0,3], y:[0,2]
+        // rectangle, with no leftover gap or concavity.
+        let lhs = utf8_series(vec![Some("POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))")]);
+        let rhs = utf8_series(vec![Some("POLYGON ((1 0, 3 0, 3 2, 1 2, 1 0))")]);
+        let (lhs, rhs) = (decode_series(&lhs, true).unwrap(), decode_series(&rhs, true).unwrap());
+
+        let union = geo_binary_dispatch(&lhs, &rhs, GeoOperation::Union).unwrap();
+        let geo_array = union.geometry().unwrap();
+        let merged = GeometryArrayIter::new(geo_array).next().unwrap().unwrap();
+        assert_eq!(merged.unsigned_area(), 6.0);
+        let bounds = merged.bounding_rect().unwrap();
+        assert_eq!((bounds.min().x, bounds.min().y), (0.0, 0.0));
+        assert_eq!((bounds.max().x, bounds.max().y), (3.0, 2.0));
+    }
+
+    #[test]
+    fn haversine_distance_matches_the_known_distance_between_two_points() {
+        // One degree of longitude along the equator is ~111.2km on the
+        // mean-radius (6371008.8m) sphere that haversine uses.
+        let lhs = utf8_series(vec![Some("POINT (0 0)")]);
+        let rhs = utf8_series(vec![Some("POINT (1 0)")]);
+        let (lhs, rhs) = (decode_series(&lhs, true).unwrap(), decode_series(&rhs, true).unwrap());
+
+        let distances = geo_binary_dispatch(&lhs, &rhs, GeoOperation::HaversineDistance).unwrap();
+        let meters = distances
+            .f64()
+            .unwrap()
+            .data()
+            .as_any()
+            .downcast_ref::<arrow2::array::PrimitiveArray<f64>>()
+            .unwrap()
+            .value(0);
+        assert!(
+            (meters - 111_195.08).abs() < 1.0,
+            "expected ~111195.08m, got {meters}"
+        );
+    }
+}